@@ -29,8 +29,13 @@ mod tests {
         assert_eq!(response.text().await.unwrap(), "OK");
     }
 
+    // Ignored by default: since chunk0-1, `create_order` performs a real OAuth2 +
+    // order-creation call against the provider configured via `--payment-provider-url`.
+    // Run this against an instance pointed at a PayU-compatible mock (or sandbox) endpoint,
+    // e.g. `TEST_PORT=3000 cargo test -- --ignored create_order_creation`.
     #[tokio::test]
     #[serial]
+    #[ignore]
     async fn create_order_creation() {
         let client = Client::new();
         let port = get_port();